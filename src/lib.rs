@@ -1,10 +1,15 @@
 #![doc = include_str!("../README.md")]
 
+mod path;
+mod stringify;
+
 use std::collections::HashMap;
 
 use once_cell::sync::Lazy;
 use serde_json::{Number, Value};
 
+pub use stringify::{stringify, StringifyOptions, StringifyResult};
+
 const ESCAPED_CHARS: Lazy<HashMap<char, &'static str>> = Lazy::new(|| {
     let mut map = HashMap::new();
     map.insert('b', r"\b");
@@ -30,6 +35,12 @@ pub enum Error {
     Int,
     #[error("Invalid unicode codepoint: {0} at position {1}")]
     InvalidUnicodeCodePoint(u32, usize),
+    #[error("Invalid JSONPath expression: {0}")]
+    InvalidJsonPath(String),
+    #[error("Number out of range at position {0}")]
+    NumberOutOfRange(usize),
+    #[error("I/O error: {0}")]
+    Io(String),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -46,6 +57,10 @@ pub struct Location {
 pub struct Options {
     /// Whether to allow big integers
     pub bigint: bool,
+    /// Whether to allow `//` line and `/* */` block comments (JSONC)
+    pub allow_comments: bool,
+    /// Whether to allow a trailing comma before `]` or `}` (JSON5)
+    pub allow_trailing_commas: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -57,9 +72,13 @@ pub enum Prop {
     ValueEnd,
 }
 
-struct Parser {
-    source: String,
-    #[allow(dead_code)]
+struct Parser<'a> {
+    /// the decoded source; for a streaming source this only holds what has
+    /// been pulled so far and grows on demand, see `feed`
+    chars: Vec<char>,
+    /// supplies more decoded chars into `chars` once `pos` catches up to its
+    /// end; `None` when the whole source was decoded up front
+    feed: Option<Box<dyn CharFeed + 'a>>,
     options: Options,
 
     line: usize,
@@ -68,12 +87,88 @@ struct Parser {
 
     /// key is the json pointer, value is the start and end location
     pointers: HashMap<String, LocationMap>,
+    /// key is the json pointer, value is the raw form of the number literal
+    numbers: HashMap<String, NumberInfo>,
+    /// the start and end location of every comment skipped so far
+    comments: Vec<(Location, Location)>,
+}
+
+/// Incrementally supplies decoded chars to a [`Parser`], so that sources
+/// which cannot be materialized up front (e.g. an `io::Read`) can still be
+/// parsed with the same random-lookahead/backtracking `Parser` methods.
+trait CharFeed {
+    /// Decode more input and append it to `buf`. Returns `Ok(true)` if at
+    /// least one char was appended, `Ok(false)` if the source is exhausted.
+    fn feed_more(&mut self, buf: &mut Vec<char>) -> Result<bool, Error>;
+}
+
+/// Reads and decodes UTF-8 incrementally from an `io::Read`, buffering any
+/// trailing bytes that don't yet form a complete char across reads.
+struct ReaderFeed<R> {
+    reader: R,
+    leftover: Vec<u8>,
+}
+
+impl<R: std::io::Read> CharFeed for ReaderFeed<R> {
+    fn feed_more(&mut self, buf: &mut Vec<char>) -> Result<bool, Error> {
+        let mut chunk = [0u8; 4096];
+        loop {
+            let n = self
+                .reader
+                .read(&mut chunk)
+                .map_err(|e| Error::Io(e.to_string()))?;
+            if n == 0 {
+                if !self.leftover.is_empty() {
+                    return Err(Error::Io(
+                        "incomplete UTF-8 sequence at end of input".to_string(),
+                    ));
+                }
+                return Ok(false);
+            }
+            self.leftover.extend_from_slice(&chunk[..n]);
+
+            match std::str::from_utf8(&self.leftover) {
+                Ok(s) => {
+                    buf.extend(s.chars());
+                    self.leftover.clear();
+                    return Ok(true);
+                }
+                Err(e) => {
+                    let valid_up_to = e.valid_up_to();
+                    if valid_up_to > 0 {
+                        let s = std::str::from_utf8(&self.leftover[..valid_up_to]).unwrap();
+                        buf.extend(s.chars());
+                        self.leftover.drain(..valid_up_to);
+                        return Ok(true);
+                    }
+                    // The bytes read so far don't contain a full char yet; read more.
+                }
+            }
+        }
+    }
+}
+
+/// Metadata about a parsed JSON number, keyed by its JSON pointer in
+/// [`ParseResult::numbers`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NumberInfo {
+    /// Whether the source literal had no `.` or exponent part
+    pub is_integer: bool,
+    /// The exact source substring, e.g. `"30"` or `"30.0"`
+    pub raw: String,
 }
 
 #[derive(Debug, Clone)]
 pub struct ParseResult {
     pub value: Value,
     pub pointers: HashMap<String, LocationMap>,
+    /// key is the json pointer, value is metadata about the number literal
+    pub numbers: HashMap<String, NumberInfo>,
+    /// the start and end location of every `//` or `/* */` comment skipped
+    /// while parsing, in source order; only populated when
+    /// [`Options::allow_comments`] is set
+    pub comments: Vec<(Location, Location)>,
 }
 
 impl ParseResult {
@@ -81,20 +176,43 @@ impl ParseResult {
     pub fn get_location(&self, ptr: &str) -> Option<&LocationMap> {
         self.pointers.get(ptr)
     }
+
+    /// Run a JSONPath expression against `self.value` and return every match
+    /// together with the [`LocationMap`] recorded for its JSON pointer.
+    ///
+    /// Supports the common subset: `$`, `.key`, `['key']`, `*`, `..`,
+    /// `[index]`, `[start:end]` slices and `[?(@.field OP value)]` filters
+    /// over scalar comparisons.
+    pub fn select(&self, path: &str) -> Result<Vec<(&Value, &LocationMap)>, Error> {
+        let pointers = path::parse_path_and_eval(path, &self.value)?;
+
+        Ok(pointers
+            .into_iter()
+            .filter_map(|ptr| {
+                let value = self.value.pointer(&ptr)?;
+                let loc = self.pointers.get(&ptr)?;
+                Some((value, loc))
+            })
+            .collect())
+    }
 }
 
 /// The location information of the json pointer
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LocationMap(HashMap<Prop, Location>);
 
 impl LocationMap {
+    pub(crate) fn new() -> Self {
+        LocationMap(HashMap::new())
+    }
+
     /// Get the location of the property
     pub fn get(&self, prop: Prop) -> Option<Location> {
         self.0.get(&prop).cloned()
     }
 
-    fn insert(&mut self, prop: Prop, loc: Location) {
+    pub(crate) fn insert(&mut self, prop: Prop, loc: Location) {
         self.0.insert(prop, loc);
     }
 
@@ -119,20 +237,40 @@ impl LocationMap {
     }
 }
 
-impl Parser {
+impl<'a> Parser<'a> {
     fn new(source: &str, options: Options) -> Self {
         Parser {
-            source: source.to_string(),
+            chars: source.chars().collect(),
+            feed: None,
+            options,
+            line: 0,
+            column: 0,
+            pos: 0,
+            pointers: HashMap::new(),
+            numbers: HashMap::new(),
+            comments: Vec::new(),
+        }
+    }
+
+    fn new_reader<R: std::io::Read + 'a>(reader: R, options: Options) -> Self {
+        Parser {
+            chars: Vec::new(),
+            feed: Some(Box::new(ReaderFeed {
+                reader,
+                leftover: Vec::new(),
+            })),
             options,
             line: 0,
             column: 0,
             pos: 0,
             pointers: HashMap::new(),
+            numbers: HashMap::new(),
+            comments: Vec::new(),
         }
     }
 
     fn parse(&mut self, ptr: &str, top_level: bool) -> Result<Value, Error> {
-        self.whitespace();
+        self.whitespace()?;
         self.map(ptr, Prop::Value);
         let c = self.get_char()?;
         let data = match c {
@@ -151,42 +289,115 @@ impl Parser {
             '"' => Value::String(self.parse_string()?),
             '[' => Value::Array(self.parse_array(ptr)?),
             '{' => self.parse_object(ptr)?,
-            '-' | '0'..='9' => Value::Number(self.parse_number()?),
+            '-' | '0'..='9' => Value::Number(self.parse_number(ptr)?),
             _ => return Err(Error::UnexpectedToken(c, self.pos)),
         };
         self.map(ptr, Prop::ValueEnd);
         // dbg!("?");
-        self.whitespace();
-        // dbg!("? ?", top_level, self.pos, self.len());
-        if top_level && self.pos < self.len() {
+        self.whitespace()?;
+        if top_level && self.peek_char()?.is_some() {
             return Err(self.unexpected_token());
         }
 
         Ok(data)
     }
 
-    #[inline]
-    fn len(&self) -> usize {
-        self.source.chars().count()
+    /// Ensure `chars[pos]` is available, pulling more input from `feed` if
+    /// necessary. Returns `false` once the source is exhausted.
+    fn ensure(&mut self, pos: usize) -> Result<bool, Error> {
+        while pos >= self.chars.len() {
+            let Some(feed) = self.feed.as_mut() else {
+                return Ok(false);
+            };
+            if !feed.feed_more(&mut self.chars)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
     }
 
-    fn whitespace(&mut self) {
+    /// Look at the char at the current position without consuming it,
+    /// pulling more input if needed. Returns `None` at end of input.
+    fn peek_char(&mut self) -> Result<Option<char>, Error> {
+        if self.ensure(self.pos)? {
+            Ok(Some(self.chars[self.pos]))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn whitespace(&mut self) -> Result<(), Error> {
         'outer: {
-            while self.pos < self.len() {
-                match self.source.chars().nth(self.pos) {
-                    Some(' ') => self.column += 1,
-                    Some('\t') => self.column += 4,
-                    Some('\r') => self.column = 0,
-                    Some('\n') => {
+            while let Some(c) = self.peek_char()? {
+                match c {
+                    ' ' => self.column += 1,
+                    '\t' => self.column += 4,
+                    '\r' => self.column = 0,
+                    '\n' => {
                         self.line += 1;
                         self.column = 0;
                     }
+                    '/' if self.options.allow_comments => {
+                        if self.skip_comment()? {
+                            continue;
+                        } else {
+                            break 'outer;
+                        }
+                    }
                     _ => break 'outer,
                 }
                 self.pos += 1;
             }
             // dbg!(1);
         }
+        Ok(())
+    }
+
+    /// If the parser is looking at a `//` or `/* */` comment, consume it
+    /// (recording its span in `self.comments`) and return `true`. Returns
+    /// `false`, leaving the position unchanged, if it is just a stray `/`.
+    fn skip_comment(&mut self) -> Result<bool, Error> {
+        if !self.ensure(self.pos + 1)? {
+            return Ok(false);
+        }
+
+        let start = self.get_location();
+        match self.chars[self.pos + 1] {
+            '/' => {
+                self.pos += 2;
+                self.column += 2;
+                while let Some(c) = self.peek_char()? {
+                    if c == '\n' {
+                        break;
+                    }
+                    self.pos += 1;
+                    self.column += 1;
+                }
+            }
+            '*' => {
+                self.pos += 2;
+                self.column += 2;
+                loop {
+                    match self.get_char()? {
+                        '*' if self.peek_char()? == Some('/') => {
+                            self.pos += 1;
+                            self.column += 1;
+                            break;
+                        }
+                        '\n' => {
+                            self.line += 1;
+                            self.column = 0;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => return Ok(false),
+        }
+
+        let end = self.get_location();
+        self.comments.push((start, end));
+        Ok(true)
     }
 
     fn parse_string(&mut self) -> Result<String, Error> {
@@ -213,50 +424,71 @@ impl Parser {
         Ok(s)
     }
 
-    fn parse_number(&mut self) -> Result<serde_json::value::Number, Error> {
+    fn parse_number(&mut self, ptr: &str) -> Result<serde_json::value::Number, Error> {
+        let start_pos = self.pos - 1;
         self.back_char();
 
         let mut num_str = String::new();
-        // let mut is_integer = true;
-        if self.next() == '-' {
+        let mut is_integer = true;
+        if self.peek_char()? == Some('-') {
             num_str.push(self.get_char()?);
         }
 
-        let next = if self.next() == '0' {
+        let next = if self.peek_char()? == Some('0') {
             self.get_char()?.to_string()
         } else {
             self.get_digits()?
         };
         num_str = num_str + &next;
 
-        if self.next() == '.' {
-            // is_integer = false;
+        if self.peek_char()? == Some('.') {
+            is_integer = false;
             num_str.push(self.get_char()?);
             num_str = num_str + &self.get_digits()?;
         }
 
-        if self.next() == 'e' || self.next() == 'E' {
-            // is_integer = false;
+        if matches!(self.peek_char()?, Some('e') | Some('E')) {
+            is_integer = false;
             num_str.push(self.get_char()?);
-            if self.next() == '-' || self.next() == '+' {
+            if matches!(self.peek_char()?, Some('-') | Some('+')) {
                 num_str.push(self.get_char()?);
             }
             num_str = num_str + &self.get_digits()?;
         }
 
-        // let res = num_str.parse::<f64>().unwrap();
+        if !self.options.bigint {
+            Self::check_number_range(&num_str, is_integer, start_pos)?;
+        }
 
-        // let n = if is_integer {
-        //     serde_json::number::N::PosInt(res)
-        // } else {
-        //     res
-        // };
+        self.numbers.insert(
+            ptr.to_string(),
+            NumberInfo {
+                is_integer,
+                raw: num_str.clone(),
+            },
+        );
 
         Ok(Number::from_string_unchecked(num_str))
     }
 
+    /// Reject numbers whose value falls outside `i64`/`u64` (for integers)
+    /// or `f64` (for floats) when [`Options::bigint`] is disabled.
+    fn check_number_range(num_str: &str, is_integer: bool, pos: usize) -> Result<(), Error> {
+        let in_range = if is_integer {
+            num_str.parse::<i64>().is_ok() || num_str.parse::<u64>().is_ok()
+        } else {
+            num_str.parse::<f64>().is_ok_and(|f| f.is_finite())
+        };
+
+        if in_range {
+            Ok(())
+        } else {
+            Err(Error::NumberOutOfRange(pos))
+        }
+    }
+
     fn parse_array(&mut self, ptr: &str) -> Result<Vec<Value>, Error> {
-        self.whitespace();
+        self.whitespace()?;
         let mut array = Vec::new();
         let c = self.get_char()?; // [
         if c == ']' {
@@ -267,22 +499,25 @@ impl Parser {
         loop {
             let item_ptr = format!("{}/{}", ptr, array.len());
             array.push(self.parse(&item_ptr, false)?);
-            self.whitespace();
+            self.whitespace()?;
             let c = self.get_char()?;
             if c == ']' {
                 break;
             } else if c != ',' {
                 return Err(self.unexpected_token());
             }
-            self.whitespace();
-            // dbg!(3);
+            self.whitespace()?;
+            if self.options.allow_trailing_commas && self.peek_char()? == Some(']') {
+                self.get_char()?;
+                break;
+            }
         }
 
         Ok(array)
     }
 
     fn parse_object(&mut self, ptr: &str) -> Result<Value, Error> {
-        self.whitespace();
+        self.whitespace()?;
         let mut object = serde_json::Map::new();
         if self.get_char()? == '}' {
             return Ok(object.into());
@@ -296,17 +531,17 @@ impl Parser {
                 return Err(self.was_unexpected_token());
             }
             let key = self.parse_string()?;
-            let prop_ptr = format!("{}/{}", ptr, Self::escape_json_pointer(&key));
+            let prop_ptr = format!("{}/{}", ptr, escape_json_pointer(&key));
             self.map_location(&prop_ptr, Prop::Key, loc);
             self.map(&prop_ptr, Prop::KeyEnd);
-            self.whitespace();
+            self.whitespace()?;
             if self.get_char()? != ':' {
                 return Err(self.was_unexpected_token());
             }
-            self.whitespace();
+            self.whitespace()?;
             let value = self.parse(&prop_ptr, false)?;
             object.insert(key, value);
-            self.whitespace();
+            self.whitespace()?;
 
             match self.get_char()? {
                 '}' => break,
@@ -314,7 +549,11 @@ impl Parser {
                 _ => return Err(self.was_unexpected_token()),
             }
 
-            self.whitespace();
+            self.whitespace()?;
+            if self.options.allow_trailing_commas && self.peek_char()? == Some('}') {
+                self.get_char()?;
+                break;
+            }
         }
         Ok(object.into())
     }
@@ -330,21 +569,12 @@ impl Parser {
 
     #[inline]
     fn get_char(&mut self) -> Result<char, Error> {
-        self.check_unexpected_eof()?;
-        let c = self.next();
+        let c = self.peek_char()?.ok_or(Error::UnexpectedEof)?;
         self.pos += 1;
         self.column += 1;
         Ok(c)
     }
 
-    #[inline]
-    fn next(&self) -> char {
-        self.source
-            .chars()
-            .nth(self.pos)
-            .expect(&format!("Unexpected EOF, pos: {}", self.pos))
-    }
-
     /// Backs up the parser one character.
     fn back_char(&mut self) {
         self.pos -= 1;
@@ -368,14 +598,12 @@ impl Parser {
 
     fn get_digits(&mut self) -> Result<String, Error> {
         let mut digits = String::new();
-        loop {
-            let c = self.next();
+        while let Some(c) = self.peek_char()? {
             if c.is_ascii_digit() {
                 digits.push(self.get_char()?);
             } else {
                 break;
             }
-            // dbg!(5);
         }
         Ok(digits)
     }
@@ -387,7 +615,7 @@ impl Parser {
     fn map_location(&mut self, ptr: impl ToString, prop: Prop, loc: Location) {
         self.pointers
             .entry(ptr.to_string())
-            .or_insert_with(|| LocationMap(HashMap::new()))
+            .or_insert_with(LocationMap::new)
             .insert(prop, loc);
     }
 
@@ -399,26 +627,23 @@ impl Parser {
         }
     }
 
-    fn unexpected_token(&self) -> Error {
-        Error::UnexpectedToken(self.next(), self.pos)
+    fn unexpected_token(&mut self) -> Error {
+        match self.peek_char() {
+            Ok(Some(c)) => Error::UnexpectedToken(c, self.pos),
+            Ok(None) => Error::UnexpectedEof,
+            Err(e) => e,
+        }
     }
 
     fn was_unexpected_token(&mut self) -> Error {
         self.back_char();
         self.unexpected_token()
     }
+}
 
-    fn check_unexpected_eof(&self) -> Result<(), Error> {
-        if self.pos >= self.len() {
-            return Err(Error::UnexpectedEof);
-        }
-
-        Ok(())
-    }
-
-    fn escape_json_pointer(s: &str) -> String {
-        s.replace("~", "~0").replace("/", "~1")
-    }
+/// Escape a JSON pointer reference token per RFC 6901 (`~` -> `~0`, `/` -> `~1`).
+pub(crate) fn escape_json_pointer(s: &str) -> String {
+    s.replace('~', "~0").replace('/', "~1")
 }
 
 pub fn parse(source: &str, options: Options) -> Result<ParseResult, Error> {
@@ -427,6 +652,22 @@ pub fn parse(source: &str, options: Options) -> Result<ParseResult, Error> {
     Ok(ParseResult {
         value,
         pointers: parser.pointers,
+        numbers: parser.numbers,
+        comments: parser.comments,
+    })
+}
+
+/// Like [`parse`], but pulls input incrementally from an `io::Read` instead
+/// of requiring it to be materialized into a `String` up front. Useful for
+/// mapping locations in large files or network streams.
+pub fn parse_reader<R: std::io::Read>(reader: R, options: Options) -> Result<ParseResult, Error> {
+    let mut parser = Parser::new_reader(reader, options);
+    let value = parser.parse("", true)?;
+    Ok(ParseResult {
+        value,
+        pointers: parser.pointers,
+        numbers: parser.numbers,
+        comments: parser.comments,
     })
 }
 
@@ -555,26 +796,6 @@ mod tests {
             serde_json::from_str::<serde_json::Value>(source).unwrap()
         );
 
-        let source = r#"{"number":1.23e+10000}"#;
-        let res = parse(source, Options::default()).unwrap();
-        assert!(res.value.is_object());
-        assert_eq!(
-            res.pointers["/number"].value(),
-            Location {
-                line: 0,
-                column: 10,
-                pos: 10
-            }
-        );
-        assert_eq!(
-            res.pointers["/number"].value_end(),
-            Location {
-                line: 0,
-                column: 21,
-                pos: 21
-            }
-        );
-
         let source = r#"{"number":-1.23e-10000}"#;
         let res = parse(source, Options::default()).unwrap();
         assert!(res.value.is_object());
@@ -663,4 +884,215 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_comments() {
+        let source = "{\n  // a line comment\n  \"foo\": /* inline */ 1,\n}";
+        let res = parse(
+            source,
+            Options {
+                allow_comments: true,
+                allow_trailing_commas: true,
+                ..Options::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(res.value, serde_json::json!({"foo": 1}));
+        assert_eq!(
+            res.comments,
+            vec![
+                (
+                    Location {
+                        line: 1,
+                        column: 2,
+                        pos: 4
+                    },
+                    Location {
+                        line: 1,
+                        column: 19,
+                        pos: 21
+                    }
+                ),
+                (
+                    Location {
+                        line: 2,
+                        column: 9,
+                        pos: 31
+                    },
+                    Location {
+                        line: 2,
+                        column: 21,
+                        pos: 43
+                    }
+                ),
+            ]
+        );
+
+        // a block comment spanning a newline resets line/column the same way
+        // whitespace does
+        let source = "/* spans\na newline */{\"a\":1}";
+        let res = parse(
+            source,
+            Options {
+                allow_comments: true,
+                ..Options::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(res.value, serde_json::json!({"a": 1}));
+        assert_eq!(
+            res.comments[0].1,
+            Location {
+                line: 1,
+                column: 12,
+                pos: 21
+            }
+        );
+    }
+
+    #[test]
+    fn test_trailing_commas() {
+        let source = r#"[1, 2,]"#;
+        let res = parse(
+            source,
+            Options {
+                allow_trailing_commas: true,
+                ..Options::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(res.value, serde_json::json!([1, 2]));
+
+        assert!(matches!(
+            parse(source, Options::default()),
+            Err(Error::UnexpectedToken(']', _))
+        ));
+
+        let source = r#"{"a":1,}"#;
+        let res = parse(
+            source,
+            Options {
+                allow_trailing_commas: true,
+                ..Options::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(res.value, serde_json::json!({"a": 1}));
+
+        assert!(matches!(
+            parse(source, Options::default()),
+            Err(Error::UnexpectedToken('}', _))
+        ));
+    }
+
+    #[test]
+    fn test_bigint() {
+        let source = r#"{"number":1.23e+10000}"#;
+        assert!(matches!(
+            parse(source, Options::default()),
+            Err(Error::NumberOutOfRange(_))
+        ));
+
+        let res = parse(
+            source,
+            Options {
+                bigint: true,
+                ..Options::default()
+            },
+        )
+        .unwrap();
+        assert!(res.value.is_object());
+        assert_eq!(
+            res.pointers["/number"].value(),
+            Location {
+                line: 0,
+                column: 10,
+                pos: 10
+            }
+        );
+        assert_eq!(
+            res.pointers["/number"].value_end(),
+            Location {
+                line: 0,
+                column: 21,
+                pos: 21
+            }
+        );
+    }
+
+    #[test]
+    fn test_number_info() {
+        let source = r#"{"number":1.23e+10000}"#;
+        let res = parse(
+            source,
+            Options {
+                bigint: true,
+                ..Options::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.numbers["/number"],
+            NumberInfo {
+                is_integer: false,
+                raw: "1.23e+10000".to_string()
+            }
+        );
+
+        let source = r#"{"number":30}"#;
+        let res = parse(source, Options::default()).unwrap();
+        assert_eq!(
+            res.numbers["/number"],
+            NumberInfo {
+                is_integer: true,
+                raw: "30".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_select() {
+        let source = r#"{"cars":["Ford","BMW","Fiat"]}"#;
+        let res = parse(source, Options::default()).unwrap();
+
+        let matches = res.select("$.cars[1]").unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].0, "BMW");
+        assert_eq!(matches[0].1, &res.pointers["/cars/1"]);
+
+        assert!(matches!(res.select("cars"), Err(Error::InvalidJsonPath(_))));
+    }
+
+    #[test]
+    fn test_parse_reader() {
+        // `parse_reader` must accept a non-`'static` reader, e.g. one
+        // borrowing a local buffer, the same way `serde_json::from_reader`
+        // does.
+        let bytes = br#"{"name":"John","age":30}"#.to_vec();
+        let res = parse_reader(bytes.as_slice(), Options::default()).unwrap();
+        assert!(res.value.is_object());
+        assert_eq!(
+            res.pointers["/name"].value(),
+            Location {
+                line: 0,
+                column: 8,
+                pos: 8
+            }
+        );
+        assert_eq!(
+            res.value,
+            serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_reader_invalid_utf8_tail() {
+        // A truncated/invalid UTF-8 byte past an otherwise complete value
+        // must surface as an error rather than being silently dropped.
+        let bytes = [br#"{"a":"b"}"#.as_slice(), &[0xFFu8]].concat();
+        assert!(matches!(
+            parse_reader(bytes.as_slice(), Options::default()),
+            Err(Error::Io(_))
+        ));
+    }
 }
@@ -0,0 +1,473 @@
+use serde_json::Value;
+
+use crate::{escape_json_pointer, Error};
+
+/// A single step of a parsed JSONPath expression.
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    /// `.key` or `['key']`
+    Key(String),
+    /// `[index]`, negative indices count from the end
+    Index(i64),
+    /// `[start:end]`, either bound may be omitted
+    Slice(Option<i64>, Option<i64>),
+    /// `*`
+    Wildcard,
+    /// `..`
+    RecursiveDescent,
+    /// `[?(@.field OP value)]`
+    Filter(Filter),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Filter {
+    field: String,
+    op: CmpOp,
+    value: Value,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CmpOp {
+    Eq,
+    Ne,
+    Gt,
+    Lt,
+    Ge,
+    Le,
+}
+
+struct Tokenizer {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Tokenizer {
+    fn new(path: &str) -> Self {
+        Tokenizer {
+            chars: path.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    fn eof(&self) -> bool {
+        self.pos >= self.chars.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), Error> {
+        if self.bump() == Some(c) {
+            Ok(())
+        } else {
+            Err(Error::InvalidJsonPath(format!("expected '{}'", c)))
+        }
+    }
+
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> String {
+        let mut s = String::new();
+        while let Some(c) = self.peek() {
+            if pred(c) {
+                s.push(c);
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        s
+    }
+}
+
+/// Parse a JSONPath expression into a sequence of [`Segment`]s.
+fn parse_path(path: &str) -> Result<Vec<Segment>, Error> {
+    let mut t = Tokenizer::new(path);
+    t.expect('$')?;
+
+    let mut segments = Vec::new();
+    while !t.eof() {
+        match t.peek() {
+            Some('.') => {
+                t.bump();
+                if t.peek() == Some('.') {
+                    t.bump();
+                    segments.push(Segment::RecursiveDescent);
+                    if t.peek() == Some('*') {
+                        t.bump();
+                        segments.push(Segment::Wildcard);
+                    } else {
+                        let key = t.take_while(is_key_char);
+                        if !key.is_empty() {
+                            segments.push(Segment::Key(key));
+                        }
+                    }
+                } else if t.peek() == Some('*') {
+                    t.bump();
+                    segments.push(Segment::Wildcard);
+                } else {
+                    let key = t.take_while(is_key_char);
+                    if key.is_empty() {
+                        return Err(Error::InvalidJsonPath("expected key after '.'".to_string()));
+                    }
+                    segments.push(Segment::Key(key));
+                }
+            }
+            Some('[') => {
+                t.bump();
+                segments.push(parse_bracket(&mut t)?);
+            }
+            _ => {
+                return Err(Error::InvalidJsonPath(format!(
+                    "unexpected character at {}",
+                    t.pos
+                )))
+            }
+        }
+    }
+
+    Ok(segments)
+}
+
+fn is_key_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+fn parse_bracket(t: &mut Tokenizer) -> Result<Segment, Error> {
+    match t.peek() {
+        Some('*') => {
+            t.bump();
+            t.expect(']')?;
+            Ok(Segment::Wildcard)
+        }
+        Some('\'') | Some('"') => {
+            let quote = t.bump().unwrap();
+            let key = t.take_while(|c| c != quote);
+            t.expect(quote)?;
+            t.expect(']')?;
+            Ok(Segment::Key(key))
+        }
+        Some('?') => {
+            t.bump();
+            t.expect('(')?;
+            let filter = parse_filter(t)?;
+            t.expect(')')?;
+            t.expect(']')?;
+            Ok(Segment::Filter(filter))
+        }
+        _ => {
+            let raw = t.take_while(|c| c != ']');
+            t.expect(']')?;
+            parse_index_or_slice(&raw)
+        }
+    }
+}
+
+fn parse_index_or_slice(raw: &str) -> Result<Segment, Error> {
+    if let Some((start, end)) = raw.split_once(':') {
+        let start = parse_opt_i64(start)?;
+        let end = parse_opt_i64(end)?;
+        Ok(Segment::Slice(start, end))
+    } else {
+        let idx = raw
+            .parse::<i64>()
+            .map_err(|_| Error::InvalidJsonPath(format!("invalid index: {}", raw)))?;
+        Ok(Segment::Index(idx))
+    }
+}
+
+fn parse_opt_i64(s: &str) -> Result<Option<i64>, Error> {
+    if s.is_empty() {
+        Ok(None)
+    } else {
+        s.parse::<i64>()
+            .map(Some)
+            .map_err(|_| Error::InvalidJsonPath(format!("invalid slice bound: {}", s)))
+    }
+}
+
+fn parse_filter(t: &mut Tokenizer) -> Result<Filter, Error> {
+    t.expect('@')?;
+    t.expect('.')?;
+    let field = t.take_while(is_key_char);
+    if field.is_empty() {
+        return Err(Error::InvalidJsonPath(
+            "expected field after '@.'".to_string(),
+        ));
+    }
+
+    t.take_while(|c| c == ' ');
+    let op = parse_cmp_op(t)?;
+    t.take_while(|c| c == ' ');
+
+    let raw = t.take_while(|c| c != ')');
+    let value = parse_filter_value(raw.trim())?;
+
+    Ok(Filter { field, op, value })
+}
+
+fn parse_cmp_op(t: &mut Tokenizer) -> Result<CmpOp, Error> {
+    let c = t
+        .bump()
+        .ok_or_else(|| Error::InvalidJsonPath("expected comparison operator".to_string()))?;
+    match c {
+        '=' => {
+            t.expect('=')?;
+            Ok(CmpOp::Eq)
+        }
+        '!' => {
+            t.expect('=')?;
+            Ok(CmpOp::Ne)
+        }
+        '>' => {
+            if t.peek() == Some('=') {
+                t.bump();
+                Ok(CmpOp::Ge)
+            } else {
+                Ok(CmpOp::Gt)
+            }
+        }
+        '<' => {
+            if t.peek() == Some('=') {
+                t.bump();
+                Ok(CmpOp::Le)
+            } else {
+                Ok(CmpOp::Lt)
+            }
+        }
+        c => Err(Error::InvalidJsonPath(format!(
+            "unsupported comparison operator '{}'",
+            c
+        ))),
+    }
+}
+
+fn parse_filter_value(raw: &str) -> Result<Value, Error> {
+    if let Some(unquoted) = raw
+        .strip_prefix('\'')
+        .and_then(|s| s.strip_suffix('\''))
+        .or_else(|| raw.strip_prefix('"').and_then(|s| s.strip_suffix('"')))
+    {
+        Ok(Value::String(unquoted.to_string()))
+    } else if raw == "true" {
+        Ok(Value::Bool(true))
+    } else if raw == "false" {
+        Ok(Value::Bool(false))
+    } else {
+        raw.parse::<f64>()
+            .map(Value::from)
+            .map_err(|_| Error::InvalidJsonPath(format!("invalid filter value: {}", raw)))
+    }
+}
+
+fn matches_filter(value: &Value, filter: &Filter) -> bool {
+    let Some(field_value) = value.get(&filter.field) else {
+        return false;
+    };
+
+    match filter.op {
+        CmpOp::Eq | CmpOp::Ne => {
+            // numbers compare by value, so `10` in the document equals `10` in the filter
+            let equal = match (field_value.as_f64(), filter.value.as_f64()) {
+                (Some(a), Some(b)) => a == b,
+                _ => field_value == &filter.value,
+            };
+            equal == (filter.op == CmpOp::Eq)
+        }
+        CmpOp::Gt | CmpOp::Lt | CmpOp::Ge | CmpOp::Le => {
+            let (Some(a), Some(b)) = (field_value.as_f64(), filter.value.as_f64()) else {
+                return false;
+            };
+            match filter.op {
+                CmpOp::Gt => a > b,
+                CmpOp::Lt => a < b,
+                CmpOp::Ge => a >= b,
+                CmpOp::Le => a <= b,
+                CmpOp::Eq | CmpOp::Ne => unreachable!(),
+            }
+        }
+    }
+}
+
+/// Resolve a (possibly negative) JSONPath index against an array length.
+fn resolve_index(idx: i64, len: usize) -> Option<usize> {
+    let resolved = if idx < 0 { idx + len as i64 } else { idx };
+    if resolved >= 0 && (resolved as usize) < len {
+        Some(resolved as usize)
+    } else {
+        None
+    }
+}
+
+/// Resolve a (possibly negative) slice bound against an array length,
+/// clamped to `0..=len`.
+fn resolve_bound(idx: i64, len: usize) -> usize {
+    let resolved = if idx < 0 { idx + len as i64 } else { idx };
+    resolved.clamp(0, len as i64) as usize
+}
+
+/// Recursively apply `segments` to `value`, appending the JSON pointer of
+/// every match to `out`.
+fn eval(value: &Value, ptr: String, segments: &[Segment], out: &mut Vec<String>) {
+    let Some((segment, rest)) = segments.split_first() else {
+        out.push(ptr);
+        return;
+    };
+
+    match segment {
+        Segment::Key(key) => {
+            if let Some(child) = value.get(key) {
+                eval(
+                    child,
+                    format!("{}/{}", ptr, escape_json_pointer(key)),
+                    rest,
+                    out,
+                );
+            }
+        }
+        Segment::Index(idx) => {
+            if let Some(array) = value.as_array() {
+                if let Some(i) = resolve_index(*idx, array.len()) {
+                    eval(&array[i], format!("{}/{}", ptr, i), rest, out);
+                }
+            }
+        }
+        Segment::Slice(start, end) => {
+            if let Some(array) = value.as_array() {
+                let len = array.len();
+                let start = start.map(|i| resolve_bound(i, len)).unwrap_or(0);
+                let end = end.map(|i| resolve_bound(i, len)).unwrap_or(len);
+                for (i, item) in array.iter().enumerate().take(end.min(len)).skip(start) {
+                    eval(item, format!("{}/{}", ptr, i), rest, out);
+                }
+            }
+        }
+        Segment::Wildcard => match value {
+            Value::Array(array) => {
+                for (i, item) in array.iter().enumerate() {
+                    eval(item, format!("{}/{}", ptr, i), rest, out);
+                }
+            }
+            Value::Object(obj) => {
+                for (key, item) in obj.iter() {
+                    eval(
+                        item,
+                        format!("{}/{}", ptr, escape_json_pointer(key)),
+                        rest,
+                        out,
+                    );
+                }
+            }
+            _ => {}
+        },
+        Segment::RecursiveDescent => {
+            eval(value, ptr.clone(), rest, out);
+            match value {
+                Value::Array(array) => {
+                    for (i, item) in array.iter().enumerate() {
+                        eval(item, format!("{}/{}", ptr, i), segments, out);
+                    }
+                }
+                Value::Object(obj) => {
+                    for (key, item) in obj.iter() {
+                        eval(
+                            item,
+                            format!("{}/{}", ptr, escape_json_pointer(key)),
+                            segments,
+                            out,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+        Segment::Filter(filter) => {
+            if let Some(array) = value.as_array() {
+                for (i, item) in array.iter().enumerate() {
+                    if matches_filter(item, filter) {
+                        eval(item, format!("{}/{}", ptr, i), rest, out);
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn parse_path_and_eval(path: &str, value: &Value) -> Result<Vec<String>, Error> {
+    let segments = parse_path(path)?;
+    let mut out = Vec::new();
+    eval(value, String::new(), &segments, &mut out);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn select(path: &str, value: &Value) -> Vec<String> {
+        parse_path_and_eval(path, value).unwrap()
+    }
+
+    #[test]
+    fn test_dot_key() {
+        let value = json!({"foo": {"bar": 1}});
+        assert_eq!(select("$.foo.bar", &value), vec!["/foo/bar"]);
+    }
+
+    #[test]
+    fn test_bracket_key() {
+        let value = json!({"foo-bar": 1});
+        assert_eq!(select("$['foo-bar']", &value), vec!["/foo-bar"]);
+    }
+
+    #[test]
+    fn test_wildcard() {
+        let value = json!({"a": 1, "b": 2});
+        let mut got = select("$.*", &value);
+        got.sort();
+        assert_eq!(got, vec!["/a", "/b"]);
+    }
+
+    #[test]
+    fn test_recursive_descent() {
+        let value = json!({"a": {"x": 1}, "b": {"x": 2}});
+        let mut got = select("$..x", &value);
+        got.sort();
+        assert_eq!(got, vec!["/a/x", "/b/x"]);
+    }
+
+    #[test]
+    fn test_index() {
+        let value = json!(["a", "b", "c"]);
+        assert_eq!(select("$[0]", &value), vec!["/0"]);
+        assert_eq!(select("$[-1]", &value), vec!["/2"]);
+    }
+
+    #[test]
+    fn test_slice() {
+        let value = json!(["a", "b", "c", "d"]);
+        assert_eq!(select("$[1:3]", &value), vec!["/1", "/2"]);
+        assert_eq!(select("$[-2:]", &value), vec!["/2", "/3"]);
+    }
+
+    #[test]
+    fn test_filter() {
+        let value = json!([{"price": 5}, {"price": 15}]);
+        assert_eq!(select("$[?(@.price > 10)]", &value), vec!["/1"]);
+    }
+
+    #[test]
+    fn test_invalid_path() {
+        let value = json!({});
+        assert!(matches!(
+            parse_path_and_eval("foo", &value),
+            Err(Error::InvalidJsonPath(_))
+        ));
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::{escape_json_pointer, Location, LocationMap, Prop};
+
+/// The stringify options
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StringifyOptions {
+    /// Whether to pretty-print the output
+    pub pretty: bool,
+    /// The indent used for each nesting level when `pretty` is set
+    pub indent: String,
+}
+
+impl Default for StringifyOptions {
+    fn default() -> Self {
+        StringifyOptions {
+            pretty: false,
+            indent: "  ".to_string(),
+        }
+    }
+}
+
+/// The result of [`stringify`]
+#[derive(Debug, Clone)]
+pub struct StringifyResult {
+    pub json: String,
+    pub pointers: HashMap<String, LocationMap>,
+}
+
+struct Writer {
+    out: String,
+    options: StringifyOptions,
+
+    line: usize,
+    column: usize,
+    pos: usize,
+
+    pointers: HashMap<String, LocationMap>,
+}
+
+impl Writer {
+    fn new(options: StringifyOptions) -> Self {
+        Writer {
+            out: String::new(),
+            options,
+            line: 0,
+            column: 0,
+            pos: 0,
+            pointers: HashMap::new(),
+        }
+    }
+
+    fn write_char(&mut self, c: char) {
+        self.out.push(c);
+        self.pos += 1;
+        match c {
+            '\n' => {
+                self.line += 1;
+                self.column = 0;
+            }
+            '\t' => self.column += 4,
+            '\r' => self.column = 0,
+            _ => self.column += 1,
+        }
+    }
+
+    fn write_str(&mut self, s: &str) {
+        for c in s.chars() {
+            self.write_char(c);
+        }
+    }
+
+    fn write_indent(&mut self, depth: usize) {
+        if self.options.pretty {
+            self.write_char('\n');
+            for _ in 0..depth {
+                self.write_str(&self.options.indent.clone());
+            }
+        }
+    }
+
+    fn map(&mut self, ptr: &str, prop: Prop) {
+        self.map_location(ptr, prop, self.get_location());
+    }
+
+    fn map_location(&mut self, ptr: &str, prop: Prop, loc: Location) {
+        self.pointers
+            .entry(ptr.to_string())
+            .or_insert_with(LocationMap::new)
+            .insert(prop, loc);
+    }
+
+    fn get_location(&self) -> Location {
+        Location {
+            line: self.line,
+            column: self.column,
+            pos: self.pos,
+        }
+    }
+
+    fn write_value(&mut self, ptr: &str, value: &Value, depth: usize) {
+        self.map(ptr, Prop::Value);
+        match value {
+            Value::Null => self.write_str("null"),
+            Value::Bool(b) => self.write_str(if *b { "true" } else { "false" }),
+            Value::Number(n) => self.write_str(&n.to_string()),
+            Value::String(s) => self.write_string(s),
+            Value::Array(arr) => self.write_array(ptr, arr, depth),
+            Value::Object(obj) => self.write_object(ptr, obj, depth),
+        }
+        self.map(ptr, Prop::ValueEnd);
+    }
+
+    fn write_array(&mut self, ptr: &str, arr: &[Value], depth: usize) {
+        self.write_char('[');
+        for (i, item) in arr.iter().enumerate() {
+            if i > 0 {
+                self.write_char(',');
+            }
+            self.write_indent(depth + 1);
+            let item_ptr = format!("{}/{}", ptr, i);
+            self.write_value(&item_ptr, item, depth + 1);
+        }
+        if !arr.is_empty() {
+            self.write_indent(depth);
+        }
+        self.write_char(']');
+    }
+
+    fn write_object(&mut self, ptr: &str, obj: &serde_json::Map<String, Value>, depth: usize) {
+        self.write_char('{');
+        for (i, (key, value)) in obj.iter().enumerate() {
+            if i > 0 {
+                self.write_char(',');
+            }
+            self.write_indent(depth + 1);
+            let prop_ptr = format!("{}/{}", ptr, escape_json_pointer(key));
+
+            let loc = self.get_location();
+            self.write_string(key);
+            self.map_location(&prop_ptr, Prop::Key, loc);
+            self.map(&prop_ptr, Prop::KeyEnd);
+
+            self.write_char(':');
+            if self.options.pretty {
+                self.write_char(' ');
+            }
+            self.write_value(&prop_ptr, value, depth + 1);
+        }
+        if !obj.is_empty() {
+            self.write_indent(depth);
+        }
+        self.write_char('}');
+    }
+
+    fn write_string(&mut self, s: &str) {
+        self.write_char('"');
+        for c in s.chars() {
+            match c {
+                '"' => self.write_str("\\\""),
+                '\\' => self.write_str("\\\\"),
+                '\u{08}' => self.write_str("\\b"),
+                '\u{0C}' => self.write_str("\\f"),
+                '\n' => self.write_str("\\n"),
+                '\r' => self.write_str("\\r"),
+                '\t' => self.write_str("\\t"),
+                c if (c as u32) < 0x20 => self.write_str(&format!("\\u{:04x}", c as u32)),
+                c => self.write_char(c),
+            }
+        }
+        self.write_char('"');
+    }
+}
+
+/// Serialize `value` to JSON text, recording a [`LocationMap`] for every JSON
+/// pointer in the output, analogous to what [`crate::parse`] recovers from
+/// existing text.
+pub fn stringify(value: &Value, options: StringifyOptions) -> StringifyResult {
+    let mut writer = Writer::new(options);
+    writer.write_value("", value, 0);
+    StringifyResult {
+        json: writer.out,
+        pointers: writer.pointers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_stringify_round_trip() {
+        let value = serde_json::json!({
+            "name": "John",
+            "age": 30,
+            "cars": ["Ford", "BMW", "Fiat"]
+        });
+
+        let res = stringify(&value, StringifyOptions::default());
+
+        let parsed = parse(&res.json, crate::Options::default()).unwrap();
+        assert_eq!(parsed.value, value);
+        assert_eq!(parsed.pointers, res.pointers);
+    }
+
+    #[test]
+    fn test_stringify_pretty_round_trip() {
+        let value = serde_json::json!({"foo": "bar", "list": [1, 2]});
+
+        let options = StringifyOptions {
+            pretty: true,
+            indent: "  ".to_string(),
+        };
+        let res = stringify(&value, options);
+        assert!(res.json.contains("\n  \"list\": [\n    1,\n    2\n  ]"));
+
+        let parsed = parse(&res.json, crate::Options::default()).unwrap();
+        assert_eq!(parsed.value, value);
+        assert_eq!(parsed.pointers, res.pointers);
+    }
+}